@@ -27,6 +27,8 @@ use middle::ty::{BuiltinBound, BoundFreeze, BoundSend, BoundSized};
 use syntax::ast;
 use syntax::ast_util::local_def;
 use syntax::attr::AttrMetaMethods;
+use syntax::attr::contains_name;
+use syntax::codemap::{DUMMY_SP, Span};
 use syntax::visit;
 use syntax::visit::Visitor;
 
@@ -34,76 +36,28 @@ use std::hashmap::HashMap;
 use std::iter::Enumerate;
 use std::vec;
 
+// The `lang_items!` macro below is the single source of truth for the set of
+// language items: it expands to the `LangItem` enum, `LanguageItems::item_name`,
+// the `items` array (sized to match the number of rows), the `item_refs` map
+// built by `LanguageItemCollector::new`, and one accessor method per item. Add
+// a lang item by adding a row here; there is nowhere else that needs to change.
+macro_rules! lang_items(
+    ($($variant:ident, $name:expr, $method:ident;)*) => (
+
 pub enum LangItem {
-    FreezeTraitLangItem,               // 0
-    SendTraitLangItem,                 // 1
-    SizedTraitLangItem,                // 2
-
-    DropTraitLangItem,                 // 3
-
-    AddTraitLangItem,                  // 4
-    AddAssignTraitLangItem,            // 5
-    SubTraitLangItem,                  // 6
-    SubAssignTraitLangItem,            // 7
-    MulTraitLangItem,                  // 8
-    MulAssignTraitLangItem,            // 9
-    DivTraitLangItem,                  // 10
-    DivAssignTraitLangItem,            // 11
-    RemTraitLangItem,                  // 12
-    RemAssignTraitLangItem,            // 13
-    NegTraitLangItem,                  // 14
-    NotTraitLangItem,                  // 15
-    BitXorTraitLangItem,               // 16
-    BitXorAssignTraitLangItem,         // 17
-    BitAndTraitLangItem,               // 18
-    BitAndAssignTraitLangItem,         // 19
-    BitOrTraitLangItem,                // 20
-    BitOrAssignTraitLangItem,          // 21
-    ShlTraitLangItem,                  // 22
-    ShlAssignTraitLangItem,            // 23
-    ShrTraitLangItem,                  // 24
-    ShrAssignTraitLangItem,            // 25
-    IndexTraitLangItem,                // 26
-
-    EqTraitLangItem,                   // 27
-    OrdTraitLangItem,                  // 28
-
-    StrEqFnLangItem,                   // 29
-    UniqStrEqFnLangItem,               // 30
-    FailFnLangItem,                    // 31
-    FailBoundsCheckFnLangItem,         // 32
-    ExchangeMallocFnLangItem,          // 33
-    ClosureExchangeMallocFnLangItem,   // 34
-    ExchangeFreeFnLangItem,            // 35
-    MallocFnLangItem,                  // 36
-    FreeFnLangItem,                    // 37
-    BorrowAsImmFnLangItem,             // 38
-    BorrowAsMutFnLangItem,             // 39
-    ReturnToMutFnLangItem,             // 40
-    CheckNotBorrowedFnLangItem,        // 41
-    StrDupUniqFnLangItem,              // 42
-    RecordBorrowFnLangItem,            // 43
-    UnrecordBorrowFnLangItem,          // 44
-
-    StartFnLangItem,                   // 45
-
-    TyDescStructLangItem,              // 46
-    TyVisitorTraitLangItem,            // 47
-    OpaqueStructLangItem,              // 48
-
-    EventLoopFactoryLangItem,          // 49
-
-    TypeIdLangItem,                    // 50
+    $($variant),*
 }
 
+pub static NUM_LANG_ITEMS: uint = lang_items!(@count $($variant),*);
+
 pub struct LanguageItems {
-    items: [Option<ast::DefId>, ..51]
+    items: [Option<ast::DefId>, ..NUM_LANG_ITEMS]
 }
 
 impl LanguageItems {
     pub fn new() -> LanguageItems {
         LanguageItems {
-            items: [ None, ..51 ]
+            items: [ None, ..NUM_LANG_ITEMS ]
         }
     }
 
@@ -113,71 +67,11 @@ impl LanguageItems {
 
     pub fn item_name(index: uint) -> &'static str {
         match index {
-            0  => "freeze",
-            1  => "send",
-            2  => "sized",
-
-            3  => "drop",
-
-            4  => "add",
-            5  => "add_assign",
-            6  => "sub",
-            7  => "sub_assign",
-            8  => "mul",
-            9  => "mul_assign",
-            10 => "div",
-            11 => "div_assign",
-            12 => "rem",
-            13 => "rem_assign",
-            14 => "neg",
-            15 => "not",
-            16 => "bitxor",
-            17 => "bitxor_assign",
-            18 => "bitand",
-            19 => "bitand_assign",
-            20 => "bitor",
-            21 => "bitor_assign",
-            22 => "shl",
-            23 => "shl_assign",
-            24 => "shr",
-            25 => "shr_assign",
-            26 => "index",
-            27 => "eq",
-            28 => "ord",
-
-            29 => "str_eq",
-            30 => "uniq_str_eq",
-            31 => "fail_",
-            32 => "fail_bounds_check",
-            33 => "exchange_malloc",
-            34 => "closure_exchange_malloc",
-            35 => "exchange_free",
-            36 => "malloc",
-            37 => "free",
-            38 => "borrow_as_imm",
-            39 => "borrow_as_mut",
-            40 => "return_to_mut",
-            41 => "check_not_borrowed",
-            42 => "strdup_uniq",
-            43 => "record_borrow",
-            44 => "unrecord_borrow",
-
-            45 => "start",
-
-            46 => "ty_desc",
-            47 => "ty_visitor",
-            48 => "opaque",
-
-            49 => "event_loop_factory",
-
-            50 => "type_id",
-
-            _ => "???"
+            $( _ if index == $variant as uint => $name, )*
+            _ => "???",
         }
     }
 
-    // FIXME #4621: Method macros sure would be nice here.
-
     pub fn require(&self, it: LangItem) -> Result<ast::DefId, ~str> {
         match self.items[it as uint] {
             Some(id) => Ok(id),
@@ -186,6 +80,31 @@ impl LanguageItems {
         }
     }
 
+    // Reports every lang item required for the current compilation mode that
+    // wasn't found, as a single batch of diagnostics, rather than letting the
+    // first missing item surface lazily wherever `require` happens to be
+    // called from deep inside some later pass.
+    pub fn check_completeness(&self, session: Session, is_no_std: bool) {
+        // A `#[no_std]`/freestanding crate supplies its own runtime support
+        // (failure unwinding, the exchange heap) or does without it, possibly
+        // via weak lang items (see `collect_item`); only the default,
+        // std-linked mode requires these items unconditionally.
+        if !is_no_std {
+            for &item in REQUIRED_LANG_ITEMS.iter() {
+                if self.items[item as uint].is_none() {
+                    session.err(format!("no lang item found: `{}`",
+                                     LanguageItems::item_name(item as uint)));
+                }
+            }
+        }
+
+        if !*session.building_library && self.start_fn().is_none() {
+            session.err(format!("no lang item found: `{}`, required to build \
+                             an executable",
+                             LanguageItems::item_name(StartFnLangItem as uint)));
+        }
+    }
+
     pub fn to_builtin_kind(&self, id: ast::DefId) -> Option<BuiltinBound> {
         if Some(id) == self.freeze_trait() {
             Some(BoundFreeze)
@@ -198,171 +117,137 @@ impl LanguageItems {
         }
     }
 
-    pub fn freeze_trait(&self) -> Option<ast::DefId> {
-        self.items[FreezeTraitLangItem as uint]
-    }
-    pub fn send_trait(&self) -> Option<ast::DefId> {
-        self.items[SendTraitLangItem as uint]
-    }
-    pub fn sized_trait(&self) -> Option<ast::DefId> {
-        self.items[SizedTraitLangItem as uint]
-    }
+    $(
+        pub fn $method(&self) -> Option<ast::DefId> {
+            self.items[$variant as uint]
+        }
+    )*
+}
 
-    pub fn drop_trait(&self) -> Option<ast::DefId> {
-        self.items[DropTraitLangItem as uint]
-    }
+impl LanguageItemCollector {
+    pub fn new(session: Session) -> LanguageItemCollector {
+        let mut item_refs = HashMap::new();
 
-    pub fn add_trait(&self) -> Option<ast::DefId> {
-        self.items[AddTraitLangItem as uint]
-    }
-    pub fn add_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[AddAssignTraitLangItem as uint]
-    }
-    pub fn sub_trait(&self) -> Option<ast::DefId> {
-        self.items[SubTraitLangItem as uint]
-    }
-    pub fn sub_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[SubAssignTraitLangItem as uint]
-    }
-    pub fn mul_trait(&self) -> Option<ast::DefId> {
-        self.items[MulTraitLangItem as uint]
-    }
-    pub fn mul_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[MulAssignTraitLangItem as uint]
-    }
-    pub fn div_trait(&self) -> Option<ast::DefId> {
-        self.items[DivTraitLangItem as uint]
-    }
-    pub fn div_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[DivAssignTraitLangItem as uint]
-    }
-    pub fn rem_trait(&self) -> Option<ast::DefId> {
-        self.items[RemTraitLangItem as uint]
-    }
-    pub fn rem_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[RemAssignTraitLangItem as uint]
-    }
-    pub fn neg_trait(&self) -> Option<ast::DefId> {
-        self.items[NegTraitLangItem as uint]
-    }
-    pub fn not_trait(&self) -> Option<ast::DefId> {
-        self.items[NotTraitLangItem as uint]
-    }
-    pub fn bitxor_trait(&self) -> Option<ast::DefId> {
-        self.items[BitXorTraitLangItem as uint]
-    }
-    pub fn bitxor_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[BitXorAssignTraitLangItem as uint]
-    }
-    pub fn bitand_trait(&self) -> Option<ast::DefId> {
-        self.items[BitAndTraitLangItem as uint]
-    }
-    pub fn bitand_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[BitAndAssignTraitLangItem as uint]
-    }
-    pub fn bitor_trait(&self) -> Option<ast::DefId> {
-        self.items[BitOrTraitLangItem as uint]
-    }
-    pub fn bitor_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[BitOrAssignTraitLangItem as uint]
-    }
-    pub fn shl_trait(&self) -> Option<ast::DefId> {
-        self.items[ShlTraitLangItem as uint]
-    }
-    pub fn shl_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[ShlAssignTraitLangItem as uint]
-    }
-    pub fn shr_trait(&self) -> Option<ast::DefId> {
-        self.items[ShrTraitLangItem as uint]
-    }
-    pub fn shr_assign_trait(&self) -> Option<ast::DefId> {
-        self.items[ShrAssignTraitLangItem as uint]
-    }
-    pub fn index_trait(&self) -> Option<ast::DefId> {
-        self.items[IndexTraitLangItem as uint]
-    }
+        $( item_refs.insert($name, $variant as uint); )*
 
-    pub fn eq_trait(&self) -> Option<ast::DefId> {
-        self.items[EqTraitLangItem as uint]
-    }
-    pub fn ord_trait(&self) -> Option<ast::DefId> {
-        self.items[OrdTraitLangItem as uint]
+        LanguageItemCollector {
+            session: session,
+            items: LanguageItems::new(),
+            item_refs: item_refs,
+            item_spans: HashMap::new(),
+            item_provenance: HashMap::new(),
+        }
     }
+}
 
-    pub fn str_eq_fn(&self) -> Option<ast::DefId> {
-        self.items[StrEqFnLangItem as uint]
-    }
-    pub fn uniq_str_eq_fn(&self) -> Option<ast::DefId> {
-        self.items[UniqStrEqFnLangItem as uint]
-    }
-    pub fn fail_fn(&self) -> Option<ast::DefId> {
-        self.items[FailFnLangItem as uint]
-    }
-    pub fn fail_bounds_check_fn(&self) -> Option<ast::DefId> {
-        self.items[FailBoundsCheckFnLangItem as uint]
-    }
-    pub fn exchange_malloc_fn(&self) -> Option<ast::DefId> {
-        self.items[ExchangeMallocFnLangItem as uint]
-    }
-    pub fn closure_exchange_malloc_fn(&self) -> Option<ast::DefId> {
-        self.items[ClosureExchangeMallocFnLangItem as uint]
-    }
-    pub fn exchange_free_fn(&self) -> Option<ast::DefId> {
-        self.items[ExchangeFreeFnLangItem as uint]
-    }
-    pub fn malloc_fn(&self) -> Option<ast::DefId> {
-        self.items[MallocFnLangItem as uint]
-    }
-    pub fn free_fn(&self) -> Option<ast::DefId> {
-        self.items[FreeFnLangItem as uint]
-    }
-    pub fn borrow_as_imm_fn(&self) -> Option<ast::DefId> {
-        self.items[BorrowAsImmFnLangItem as uint]
-    }
-    pub fn borrow_as_mut_fn(&self) -> Option<ast::DefId> {
-        self.items[BorrowAsMutFnLangItem as uint]
-    }
-    pub fn return_to_mut_fn(&self) -> Option<ast::DefId> {
-        self.items[ReturnToMutFnLangItem as uint]
-    }
-    pub fn check_not_borrowed_fn(&self) -> Option<ast::DefId> {
-        self.items[CheckNotBorrowedFnLangItem as uint]
-    }
-    pub fn strdup_uniq_fn(&self) -> Option<ast::DefId> {
-        self.items[StrDupUniqFnLangItem as uint]
-    }
-    pub fn record_borrow_fn(&self) -> Option<ast::DefId> {
-        self.items[RecordBorrowFnLangItem as uint]
-    }
-    pub fn unrecord_borrow_fn(&self) -> Option<ast::DefId> {
-        self.items[UnrecordBorrowFnLangItem as uint]
-    }
-    pub fn start_fn(&self) -> Option<ast::DefId> {
-        self.items[StartFnLangItem as uint]
-    }
-    pub fn ty_desc(&self) -> Option<ast::DefId> {
-        self.items[TyDescStructLangItem as uint]
-    }
-    pub fn ty_visitor(&self) -> Option<ast::DefId> {
-        self.items[TyVisitorTraitLangItem as uint]
-    }
-    pub fn opaque(&self) -> Option<ast::DefId> {
-        self.items[OpaqueStructLangItem as uint]
-    }
-    pub fn event_loop_factory(&self) -> Option<ast::DefId> {
-        self.items[EventLoopFactoryLangItem as uint]
-    }
-    pub fn type_id(&self) -> Option<ast::DefId> {
-        self.items[TypeIdLangItem as uint]
-    }
+    );
+
+    // Count the number of rows by replacing each variant with `1u` and
+    // summing; this is the array size the macro expansion above relies on.
+    (@count) => (0u);
+    (@count $_variant:ident $(, $rest:ident)*) => (
+        1u + lang_items!(@count $($rest),*)
+    );
+)
+
+lang_items! {
+    FreezeTraitLangItem,                "freeze",                   freeze_trait;
+    SendTraitLangItem,                  "send",                     send_trait;
+    SizedTraitLangItem,                 "sized",                    sized_trait;
+
+    DropTraitLangItem,                  "drop",                     drop_trait;
+
+    AddTraitLangItem,                   "add",                      add_trait;
+    AddAssignTraitLangItem,             "add_assign",               add_assign_trait;
+    SubTraitLangItem,                   "sub",                      sub_trait;
+    SubAssignTraitLangItem,             "sub_assign",               sub_assign_trait;
+    MulTraitLangItem,                   "mul",                      mul_trait;
+    MulAssignTraitLangItem,             "mul_assign",               mul_assign_trait;
+    DivTraitLangItem,                   "div",                      div_trait;
+    DivAssignTraitLangItem,             "div_assign",               div_assign_trait;
+    RemTraitLangItem,                   "rem",                      rem_trait;
+    RemAssignTraitLangItem,             "rem_assign",               rem_assign_trait;
+    NegTraitLangItem,                   "neg",                      neg_trait;
+    NotTraitLangItem,                   "not",                      not_trait;
+    BitXorTraitLangItem,                "bitxor",                   bitxor_trait;
+    BitXorAssignTraitLangItem,          "bitxor_assign",            bitxor_assign_trait;
+    BitAndTraitLangItem,                "bitand",                   bitand_trait;
+    BitAndAssignTraitLangItem,          "bitand_assign",            bitand_assign_trait;
+    BitOrTraitLangItem,                 "bitor",                    bitor_trait;
+    BitOrAssignTraitLangItem,           "bitor_assign",             bitor_assign_trait;
+    ShlTraitLangItem,                   "shl",                      shl_trait;
+    ShlAssignTraitLangItem,             "shl_assign",               shl_assign_trait;
+    ShrTraitLangItem,                   "shr",                      shr_trait;
+    ShrAssignTraitLangItem,             "shr_assign",               shr_assign_trait;
+    IndexTraitLangItem,                 "index",                    index_trait;
+    DerefTraitLangItem,                 "deref",                    deref_trait;
+    DerefMutTraitLangItem,              "deref_mut",                deref_mut_trait;
+
+    FnTraitLangItem,                    "fn",                       fn_trait;
+    FnMutTraitLangItem,                 "fn_mut",                   fn_mut_trait;
+    FnOnceTraitLangItem,                "fn_once",                  fn_once_trait;
+
+    SliceTraitLangItem,                 "slice",                    slice_trait;
+    SliceMutTraitLangItem,              "slice_mut",                slice_mut_trait;
+
+    EqTraitLangItem,                    "eq",                       eq_trait;
+    OrdTraitLangItem,                   "ord",                      ord_trait;
+
+    StrEqFnLangItem,                    "str_eq",                   str_eq_fn;
+    UniqStrEqFnLangItem,                "uniq_str_eq",              uniq_str_eq_fn;
+    FailFnLangItem,                     "fail_",                    fail_fn;
+    FailBoundsCheckFnLangItem,          "fail_bounds_check",        fail_bounds_check_fn;
+    ExchangeMallocFnLangItem,           "exchange_malloc",          exchange_malloc_fn;
+    ClosureExchangeMallocFnLangItem,    "closure_exchange_malloc",  closure_exchange_malloc_fn;
+    ExchangeFreeFnLangItem,             "exchange_free",            exchange_free_fn;
+    MallocFnLangItem,                   "malloc",                  malloc_fn;
+    FreeFnLangItem,                     "free",                    free_fn;
+    BorrowAsImmFnLangItem,              "borrow_as_imm",           borrow_as_imm_fn;
+    BorrowAsMutFnLangItem,              "borrow_as_mut",           borrow_as_mut_fn;
+    ReturnToMutFnLangItem,              "return_to_mut",           return_to_mut_fn;
+    CheckNotBorrowedFnLangItem,         "check_not_borrowed",      check_not_borrowed_fn;
+    StrDupUniqFnLangItem,               "strdup_uniq",             strdup_uniq_fn;
+    RecordBorrowFnLangItem,             "record_borrow",           record_borrow_fn;
+    UnrecordBorrowFnLangItem,           "unrecord_borrow",         unrecord_borrow_fn;
+
+    StartFnLangItem,                    "start",                   start_fn;
+
+    TyDescStructLangItem,               "ty_desc",                 ty_desc;
+    TyVisitorTraitLangItem,             "ty_visitor",              ty_visitor;
+    OpaqueStructLangItem,               "opaque",                  opaque;
+
+    EventLoopFactoryLangItem,           "event_loop_factory",      event_loop_factory;
+
+    TypeIdLangItem,                     "type_id",                 type_id;
 }
 
+// Lang items required by the default, std-linked compilation mode; skipped
+// for `#[no_std]`/freestanding crates, which may supply their own or do
+// without. `start` is checked separately, since it's only required when
+// building an executable. See `LanguageItems::check_completeness`.
+static REQUIRED_LANG_ITEMS: &'static [LangItem] = &[
+    FailFnLangItem,
+    FailBoundsCheckFnLangItem,
+    ExchangeMallocFnLangItem,
+    ExchangeFreeFnLangItem,
+];
+
 struct LanguageItemCollector {
     items: LanguageItems,
 
     session: Session,
 
     item_refs: HashMap<&'static str, uint>,
+
+    // The span of the definition currently recorded for each item (the first
+    // one, or a strong one that overrode a weak one), kept around so a later
+    // duplicate can point back at it.
+    item_spans: HashMap<uint, Span>,
+
+    // Whether each defined item was declared weak (`#[weak_lang]`) or strong
+    // (`#[lang]`), so a later strong definition can silently override an
+    // earlier weak one instead of being rejected as a duplicate.
+    item_provenance: HashMap<uint, bool>,
 }
 
 struct LanguageItemVisitor<'self> {
@@ -372,12 +257,13 @@ struct LanguageItemVisitor<'self> {
 impl<'self> Visitor<()> for LanguageItemVisitor<'self> {
     fn visit_item(&mut self, item: @ast::item, _: ()) {
         match extract(item.attrs) {
-            Some(value) => {
+            Some((value, is_weak)) => {
                 let item_index = self.this.item_refs.find_equiv(&value).map(|x| *x);
 
                 match item_index {
                     Some(item_index) => {
-                        self.this.collect_item(item_index, local_def(item.id))
+                        self.this.collect_item(item_index, local_def(item.id),
+                                                item.span, is_weak)
                     }
                     None => {}
                 }
@@ -390,88 +276,51 @@ impl<'self> Visitor<()> for LanguageItemVisitor<'self> {
 }
 
 impl LanguageItemCollector {
-    pub fn new(session: Session) -> LanguageItemCollector {
-        let mut item_refs = HashMap::new();
-
-        item_refs.insert("freeze", FreezeTraitLangItem as uint);
-        item_refs.insert("send", SendTraitLangItem as uint);
-        item_refs.insert("sized", SizedTraitLangItem as uint);
-
-        item_refs.insert("drop", DropTraitLangItem as uint);
-
-        item_refs.insert("add", AddTraitLangItem as uint);
-        item_refs.insert("add_assign", AddAssignTraitLangItem as uint);
-        item_refs.insert("sub", SubTraitLangItem as uint);
-        item_refs.insert("sub_assign", SubAssignTraitLangItem as uint);
-        item_refs.insert("mul", MulTraitLangItem as uint);
-        item_refs.insert("mul_assign", MulAssignTraitLangItem as uint);
-        item_refs.insert("div", DivTraitLangItem as uint);
-        item_refs.insert("div_assign", DivAssignTraitLangItem as uint);
-        item_refs.insert("rem", RemTraitLangItem as uint);
-        item_refs.insert("rem_assign", RemAssignTraitLangItem as uint);
-        item_refs.insert("neg", NegTraitLangItem as uint);
-        item_refs.insert("not", NotTraitLangItem as uint);
-        item_refs.insert("bitxor", BitXorTraitLangItem as uint);
-        item_refs.insert("bitxor_assign", BitXorAssignTraitLangItem as uint);
-        item_refs.insert("bitand", BitAndTraitLangItem as uint);
-        item_refs.insert("bitand_assign", BitAndAssignTraitLangItem as uint);
-        item_refs.insert("bitor", BitOrTraitLangItem as uint);
-        item_refs.insert("bitor_assign", BitOrAssignTraitLangItem as uint);
-        item_refs.insert("shl", ShlTraitLangItem as uint);
-        item_refs.insert("shl_assign", ShlAssignTraitLangItem as uint);
-        item_refs.insert("shr", ShrTraitLangItem as uint);
-        item_refs.insert("shr_assign", ShrAssignTraitLangItem as uint);
-        item_refs.insert("index", IndexTraitLangItem as uint);
-
-        item_refs.insert("eq", EqTraitLangItem as uint);
-        item_refs.insert("ord", OrdTraitLangItem as uint);
-
-        item_refs.insert("str_eq", StrEqFnLangItem as uint);
-        item_refs.insert("uniq_str_eq", UniqStrEqFnLangItem as uint);
-        item_refs.insert("fail_", FailFnLangItem as uint);
-        item_refs.insert("fail_bounds_check",
-                         FailBoundsCheckFnLangItem as uint);
-        item_refs.insert("exchange_malloc", ExchangeMallocFnLangItem as uint);
-        item_refs.insert("closure_exchange_malloc", ClosureExchangeMallocFnLangItem as uint);
-        item_refs.insert("exchange_free", ExchangeFreeFnLangItem as uint);
-        item_refs.insert("malloc", MallocFnLangItem as uint);
-        item_refs.insert("free", FreeFnLangItem as uint);
-        item_refs.insert("borrow_as_imm", BorrowAsImmFnLangItem as uint);
-        item_refs.insert("borrow_as_mut", BorrowAsMutFnLangItem as uint);
-        item_refs.insert("return_to_mut", ReturnToMutFnLangItem as uint);
-        item_refs.insert("check_not_borrowed",
-                         CheckNotBorrowedFnLangItem as uint);
-        item_refs.insert("strdup_uniq", StrDupUniqFnLangItem as uint);
-        item_refs.insert("record_borrow", RecordBorrowFnLangItem as uint);
-        item_refs.insert("unrecord_borrow", UnrecordBorrowFnLangItem as uint);
-        item_refs.insert("start", StartFnLangItem as uint);
-        item_refs.insert("ty_desc", TyDescStructLangItem as uint);
-        item_refs.insert("ty_visitor", TyVisitorTraitLangItem as uint);
-        item_refs.insert("opaque", OpaqueStructLangItem as uint);
-        item_refs.insert("event_loop_factory", EventLoopFactoryLangItem as uint);
-        item_refs.insert("type_id", TypeIdLangItem as uint);
-
-        LanguageItemCollector {
-            session: session,
-            items: LanguageItems::new(),
-            item_refs: item_refs
-        }
-    }
+    pub fn collect_item(&mut self,
+                         item_index: uint,
+                         item_def_id: ast::DefId,
+                         span: Span,
+                         is_weak: bool) {
+        let existing = match (self.items.items[item_index],
+                               self.item_provenance.find(&item_index)) {
+            (Some(original_def_id), Some(&original_is_weak))
+                    if original_def_id != item_def_id => {
+                Some(original_is_weak)
+            }
+            _ => None,
+        };
 
-    pub fn collect_item(&mut self, item_index: uint, item_def_id: ast::DefId) {
-        // Check for duplicates.
-        match self.items.items[item_index] {
-            Some(original_def_id) if original_def_id != item_def_id => {
-                self.session.err(format!("duplicate entry for `{}`",
-                                      LanguageItems::item_name(item_index)));
+        match existing {
+            Some(true) if !is_weak => {
+                // A strong definition overrides an earlier weak one.
             }
-            Some(_) | None => {
-                // OK.
+            Some(false) if is_weak => {
+                // A weak definition never overrides an existing strong one.
+                return;
+            }
+            Some(_) => {
+                // weak-over-weak or strong-over-strong: a real duplicate.
+                self.session.span_err(span,
+                    format!("duplicate entry for `{}`",
+                            LanguageItems::item_name(item_index)));
+                match self.item_spans.find(&item_index) {
+                    Some(&original_span) => {
+                        self.session.span_note(original_span,
+                            "first definition is here");
+                    }
+                    None => {}
+                }
+                return;
+            }
+            None => {
+                // First definition of this item (or a re-visit of the same
+                // definition), nothing to check.
             }
         }
 
-        // Matched.
         self.items.items[item_index] = Some(item_def_id);
+        self.item_provenance.insert(item_index, is_weak);
+        self.item_spans.insert(item_index, span);
     }
 
     pub fn collect_local_language_items(&mut self, crate: &ast::Crate) {
@@ -484,7 +333,10 @@ impl LanguageItemCollector {
         iter_crate_data(crate_store, |crate_number, _crate_metadata| {
             each_lang_item(crate_store, crate_number, |node_id, item_index| {
                 let def_id = ast::DefId { crate: crate_number, node: node_id };
-                self.collect_item(item_index, def_id);
+                // Cross-crate lang items have no local span to point at, and
+                // metadata doesn't yet round-trip the weak/strong distinction,
+                // so treat them as strong for now.
+                self.collect_item(item_index, def_id, DUMMY_SP, false);
                 true
             });
         })
@@ -496,11 +348,16 @@ impl LanguageItemCollector {
     }
 }
 
-pub fn extract(attrs: &[ast::Attribute]) -> Option<@str> {
+// Returns the lang item name an item is tagged with, together with whether
+// it was declared weak (`#[weak_lang]`) rather than strong (`#[lang]`).
+pub fn extract(attrs: &[ast::Attribute]) -> Option<(@str, bool)> {
     for attribute in attrs.iter() {
         match attribute.name_str_pair() {
             Some((key, value)) if "lang" == key => {
-                return Some(value);
+                return Some((value, false));
+            }
+            Some((key, value)) if "weak_lang" == key => {
+                return Some((value, true));
             }
             Some(..) | None => {}
         }
@@ -515,6 +372,7 @@ pub fn collect_language_items(crate: &ast::Crate,
     let mut collector = LanguageItemCollector::new(session);
     collector.collect(crate);
     let LanguageItemCollector { items, .. } = collector;
+    items.check_completeness(session, contains_name(crate.attrs, "no_std"));
     session.abort_if_errors();
     items
 }
@@ -542,6 +542,285 @@ pub trait Index<Index,Result> {
     fn index(&self, index: &Index) -> Result;
 }
 
+/**
+ *
+ * The `Deref` trait is used to specify the functionality of dereferencing
+ * operations like `*v`.
+ *
+ * # Example
+ *
+ * A trivial implementation of `Deref`. The compiler does not translate the
+ * `*` operator into a call of `deref`, so the `*Foo(1)` in `main` does not
+ * reach `deref`; the example is marked `ignore` and is not compiled.
+ *
+ * ```ignore
+ * struct Foo(int);
+ *
+ * impl Deref<int> for Foo {
+ *     fn deref<'a>(&'a self) -> &'a int {
+ *         println("Deref-ing!");
+ *         let Foo(ref x) = *self;
+ *         x
+ *     }
+ * }
+ *
+ * fn main() {
+ *     *Foo(1);
+ * }
+ * ```
+ */
+#[lang="deref"]
+pub trait Deref<Result> {
+    fn deref<'a>(&'a self) -> &'a Result;
+}
+
+/**
+ *
+ * The `DerefMut` trait is used to specify the functionality of dereferencing
+ * mutably, as in `*v = ...`.
+ *
+ * # Example
+ *
+ * A trivial implementation of `DerefMut`. As with `Deref`, the compiler does
+ * not translate a mutable use of `*` into a call of `deref_mut`, so the
+ * `*Foo(1) = 2` in `main` does not reach it; the example is marked `ignore`.
+ *
+ * ```ignore
+ * struct Foo(int);
+ *
+ * impl Deref<int> for Foo {
+ *     fn deref<'a>(&'a self) -> &'a int {
+ *         let Foo(ref x) = *self;
+ *         x
+ *     }
+ * }
+ *
+ * impl DerefMut<int> for Foo {
+ *     fn deref_mut<'a>(&'a mut self) -> &'a mut int {
+ *         println("Deref-ing mutably!");
+ *         let Foo(ref mut x) = *self;
+ *         x
+ *     }
+ * }
+ *
+ * fn main() {
+ *     *Foo(1) = 2;
+ * }
+ * ```
+ */
+#[lang="deref_mut"]
+pub trait DerefMut<Result>: Deref<Result> {
+    fn deref_mut<'a>(&'a mut self) -> &'a mut Result;
+}
+
+/**
+ *
+ * The `Fn` trait is used to specify the functionality of call operations like
+ * `obj(arg)`, for objects that can be called by shared reference.
+ *
+ * `Args` is a tuple of the call's argument types. `Fn` has `FnMut` as a
+ * supertrait, which in turn has `FnOnce` as a supertrait, so a type that
+ * implements `Fn` must also implement `FnMut` and `FnOnce`.
+ *
+ * # Example
+ *
+ * A trivial implementation of `Fn`. The compiler does not translate the call
+ * syntax `Foo(1)` into a call of `call`, so `main` does not reach it; the
+ * example is marked `ignore` and is not compiled.
+ *
+ * ```ignore
+ * struct Foo;
+ *
+ * impl FnOnce<(int,), int> for Foo {
+ *     fn call_once(self, args: (int,)) -> int {
+ *         let (x,) = args;
+ *         x
+ *     }
+ * }
+ *
+ * impl FnMut<(int,), int> for Foo {
+ *     fn call_mut(&mut self, args: (int,)) -> int {
+ *         let (x,) = args;
+ *         x
+ *     }
+ * }
+ *
+ * impl Fn<(int,), int> for Foo {
+ *     fn call(&self, args: (int,)) -> int {
+ *         println("Calling!");
+ *         let (x,) = args;
+ *         x
+ *     }
+ * }
+ *
+ * fn main() {
+ *     Foo(1);
+ * }
+ * ```
+ */
+#[lang="fn"]
+pub trait Fn<Args,Result>: FnMut<Args,Result> {
+    fn call(&self, args: Args) -> Result;
+}
+
+/**
+ *
+ * The `FnMut` trait is used to specify the functionality of call operations
+ * for objects that need a mutable reference to be called.
+ *
+ * # Example
+ *
+ * A trivial implementation of `FnMut`. The compiler does not translate call
+ * syntax into a call of `call_mut`; the example is marked `ignore` and is
+ * not compiled.
+ *
+ * ```ignore
+ * struct Foo {
+ *     calls: int
+ * }
+ *
+ * impl FnOnce<(int,), int> for Foo {
+ *     fn call_once(self, args: (int,)) -> int {
+ *         let (x,) = args;
+ *         x
+ *     }
+ * }
+ *
+ * impl FnMut<(int,), int> for Foo {
+ *     fn call_mut(&mut self, args: (int,)) -> int {
+ *         self.calls += 1;
+ *         let (x,) = args;
+ *         x
+ *     }
+ * }
+ * ```
+ */
+#[lang="fn_mut"]
+pub trait FnMut<Args,Result>: FnOnce<Args,Result> {
+    fn call_mut(&mut self, args: Args) -> Result;
+}
+
+/**
+ *
+ * The `FnOnce` trait is used to specify the functionality of call operations
+ * for objects that can only be called once, consuming themselves in the
+ * process.
+ *
+ * # Example
+ *
+ * A trivial implementation of `FnOnce`. The compiler does not translate call
+ * syntax into a call of `call_once`, and `FnOnce` is not in the prelude; the
+ * example is marked `ignore` and is not compiled.
+ *
+ * ```ignore
+ * struct Foo(~str);
+ *
+ * impl FnOnce<(int,), ~str> for Foo {
+ *     fn call_once(self, args: (int,)) -> ~str {
+ *         let Foo(s) = self;
+ *         let (_,) = args;
+ *         s
+ *     }
+ * }
+ * ```
+ */
+#[lang="fn_once"]
+pub trait FnOnce<Args,Result> {
+    fn call_once(self, args: Args) -> Result;
+}
+
+/**
+ *
+ * The `Slice` trait is used to specify the functionality of contiguous range
+ * indexing operations like `arr[from..to]`.
+ *
+ * # Example
+ *
+ * A trivial implementation of `Slice`, with one method for each of the range
+ * forms `Foo[0..1]`, `Foo[0..]`, `Foo[..1]` and `Foo[..]`. The compiler does
+ * not translate these forms into calls of the methods, so `main` does not
+ * reach them; the example is marked `ignore` and is not compiled.
+ *
+ * ```ignore
+ * struct Foo;
+ *
+ * impl Slice<int, Foo> for Foo {
+ *     fn as_slice_<'a>(&'a self) -> &'a Foo {
+ *         println("Slicing!");
+ *         self
+ *     }
+ *     fn slice_from<'a>(&'a self, _from: &int) -> &'a Foo {
+ *         println("Slicing!");
+ *         self
+ *     }
+ *     fn slice_to<'a>(&'a self, _to: &int) -> &'a Foo {
+ *         println("Slicing!");
+ *         self
+ *     }
+ *     fn slice<'a>(&'a self, _from: &int, _to: &int) -> &'a Foo {
+ *         println("Slicing!");
+ *         self
+ *     }
+ * }
+ *
+ * fn main() {
+ *     Foo[0..1];
+ *     Foo[0..];
+ *     Foo[..1];
+ *     Foo[..];
+ * }
+ * ```
+ */
+#[lang="slice"]
+pub trait Slice<Idx,Result> {
+    fn as_slice_<'a>(&'a self) -> &'a Result;
+    fn slice_from<'a>(&'a self, from: &Idx) -> &'a Result;
+    fn slice_to<'a>(&'a self, to: &Idx) -> &'a Result;
+    fn slice<'a>(&'a self, from: &Idx, to: &Idx) -> &'a Result;
+}
+
+/**
+ *
+ * The `SliceMut` trait is used to specify the functionality of contiguous
+ * range indexing operations that require a mutable borrow, like
+ * `arr[from..to] = ...`.
+ *
+ * # Example
+ *
+ * A trivial implementation of `SliceMut`. The compiler does not translate
+ * range indexing in a mutable context into calls of these methods, and
+ * `Slice`/`SliceMut` are not in the prelude; the example is marked `ignore`
+ * and is not compiled.
+ *
+ * ```ignore
+ * struct Foo;
+ *
+ * impl Slice<int, Foo> for Foo {
+ *     fn as_slice_<'a>(&'a self) -> &'a Foo { self }
+ *     fn slice_from<'a>(&'a self, _from: &int) -> &'a Foo { self }
+ *     fn slice_to<'a>(&'a self, _to: &int) -> &'a Foo { self }
+ *     fn slice<'a>(&'a self, _from: &int, _to: &int) -> &'a Foo { self }
+ * }
+ *
+ * impl SliceMut<int, Foo> for Foo {
+ *     fn as_mut_slice_<'a>(&'a mut self) -> &'a mut Foo {
+ *         println("Slicing mutably!");
+ *         self
+ *     }
+ *     fn slice_from_mut<'a>(&'a mut self, _from: &int) -> &'a mut Foo { self }
+ *     fn slice_to_mut<'a>(&'a mut self, _to: &int) -> &'a mut Foo { self }
+ *     fn slice_mut<'a>(&'a mut self, _from: &int, _to: &int) -> &'a mut Foo { self }
+ * }
+ * ```
+ */
+#[lang="slice_mut"]
+pub trait SliceMut<Idx,Result>: Slice<Idx,Result> {
+    fn as_mut_slice_<'a>(&'a mut self) -> &'a mut Result;
+    fn slice_from_mut<'a>(&'a mut self, from: &Idx) -> &'a mut Result;
+    fn slice_to_mut<'a>(&'a mut self, to: &Idx) -> &'a mut Result;
+    fn slice_mut<'a>(&'a mut self, from: &Idx, to: &Idx) -> &'a mut Result;
+}
+
 #[cfg(test)]
 mod bench {
 